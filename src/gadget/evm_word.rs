@@ -39,6 +39,27 @@ pub(crate) fn encode<F: FieldExt>(vals: impl Iterator<Item = u8>, r: F) -> F {
     })
 }
 
+// Returns the decomposition of a little-endian 256-bit word into `N` limbs of
+// `BITS` bits each, least significant limb first. For example,
+// `to_limbs::<F, 16, 16>` gives 16-bit limbs and `to_limbs::<F, 32, 8>` gives
+// the byte cells witnessed by `WordConfig`.
+pub(crate) fn to_limbs<F: FieldExt, const N: usize, const BITS: usize>(word: [u8; 32]) -> [F; N] {
+    assert_eq!(N * BITS, 256, "limbs must cover exactly 256 bits");
+    assert!(BITS <= 64, "limbs wider than 64 bits are not supported");
+
+    let mut limbs = [F::zero(); N];
+    for (idx, limb) in limbs.iter_mut().enumerate() {
+        let value = (0..BITS).fold(0u64, |acc, bit| {
+            let pos = idx * BITS + bit;
+            let bit_val = (word[pos / 8] >> (pos % 8)) & 1;
+            acc | ((bit_val as u64) << bit)
+        });
+        *limb = F::from_u64(value);
+    }
+
+    limbs
+}
+
 /// A 256-bit word represented in the circuit as 32 bytes.
 pub(crate) struct Word<F: FieldExt>([Variable<u8, F>; 32]);
 
@@ -266,4 +287,25 @@ mod tests {
             assert_eq!(prover.verify(), Ok(()))
         }
     }
+
+    #[test]
+    fn word_limbs() {
+        let word = pallas::Base::rand();
+        let bytes = word.to_bytes();
+
+        // 8-bit limbs are the bytes themselves.
+        let limbs = to_limbs::<pallas::Base, 32, 8>(bytes);
+        for (limb, byte) in limbs.iter().zip(bytes.iter()) {
+            assert_eq!(*limb, pallas::Base::from_u64(*byte as u64));
+        }
+
+        // Recomposing the 16-bit limbs in base 2^16 gives back the word.
+        let limbs = to_limbs::<pallas::Base, 16, 16>(bytes);
+        let base = pallas::Base::from_u64(1 << 16);
+        let recomposed = limbs
+            .iter()
+            .rev()
+            .fold(pallas::Base::zero(), |acc, limb| acc * base + *limb);
+        assert_eq!(recomposed, word);
+    }
 }
@@ -38,9 +38,10 @@ Example bus mapping:
 #[derive(Copy, Clone, Debug)]
 struct MemoryAddress<F: FieldExt>(F);
 
-/// Global counter
+/// Global counter. Stored as a u64 so its range does not depend on the
+/// platform's pointer width.
 #[derive(Copy, Clone, Debug)]
-struct GlobalCounter(usize);
+struct GlobalCounter(u64);
 
 /// TODO: In the EVM we can only read memory values in 32 bytes, but can write either
 /// single-byte or 32-byte chunks. In zkEVM:
@@ -101,12 +102,27 @@ Example bus mapping:
 /// and opcode details as well.
 #[derive(Clone, Debug)]
 pub(crate) struct BusMapping<F: FieldExt> {
-    global_counter: Variable<usize, F>,
+    global_counter: Variable<u64, F>,
     memory_flag: Variable<bool, F>,
     memory_address: Variable<F, F>,
     memory_value: Variable<F, F>,
 }
 
+impl<F: FieldExt> BusMapping<F> {
+    /// Returns the random linear combination of
+    /// (global_counter, memory_flag, memory_address, memory_value),
+    /// which is the value folded into the bus mapping lookup.
+    fn encoded_val(&self, r: F) -> Option<F> {
+        let vals = [
+            self.global_counter.field_elem?,
+            self.memory_flag.field_elem?,
+            self.memory_address.field_elem?,
+            self.memory_value.field_elem?,
+        ];
+        Some(vals.iter().fold(F::zero(), |acc, val| acc * r + *val))
+    }
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct Config<F: FieldExt> {
     q_memory: Column<Fixed>,
@@ -263,7 +279,6 @@ impl<F: FieldExt> Config<F> {
                         )?;
                         offset += 1;
 
-                        // TODO: order by global_counter
                         bus_mappings.push(bus_mapping);
                     }
                 }
@@ -272,6 +287,10 @@ impl<F: FieldExt> Config<F> {
             },
         )?;
 
+        // Memory rows are ordered by address, but the bus mapping is
+        // looked up in global counter order.
+        bus_mappings.sort_by_key(|bus_mapping| bus_mapping.global_counter.value);
+
         Ok(bus_mappings)
     }
 
@@ -326,7 +345,7 @@ impl<F: FieldExt> Config<F> {
             let value = read_write
                 .as_ref()
                 .map(|read_write| read_write.global_counter().0);
-            let field_elem = value.map(|value| F::from_u64(value as u64));
+            let field_elem = value.map(F::from_u64);
 
             let cell = region.assign_advice(
                 || "global counter",
@@ -335,7 +354,7 @@ impl<F: FieldExt> Config<F> {
                 || field_elem.ok_or(Error::SynthesisError),
             )?;
 
-            Variable::<usize, F> {
+            Variable::<u64, F> {
                 cell,
                 field_elem,
                 value,
@@ -423,7 +442,22 @@ mod tests {
                 config: Self::Config,
                 layouter: impl Layouter<F>,
             ) -> Result<(), Error> {
-                config.assign(layouter, self.ops.clone())?;
+                let bus_mappings = config.assign(layouter, self.ops.clone())?;
+
+                let global_counters: Vec<_> = bus_mappings
+                    .iter()
+                    .map(|bus_mapping| bus_mapping.global_counter.value)
+                    .collect();
+                assert_eq!(
+                    global_counters,
+                    vec![Some(12), Some(17), Some(24), Some(89)]
+                );
+
+                // The first row is the write of 12 to address 0 at global counter 12.
+                let r = F::from_u64(7);
+                let twelve = F::from_u64(12);
+                let expected = ((twelve * r + F::one()) * r + F::zero()) * r + twelve;
+                assert_eq!(bus_mappings[0].encoded_val(r), Some(expected));
 
                 Ok(())
             }